@@ -7,25 +7,34 @@ use std::fmt;
 use std::str::FromStr;
 
 #[derive(Debug)]
-struct MyError {
-    details: String
+enum ParsePersonError {
+    Empty,
+    WrongFieldCount { got: usize },
+    MissingName,
+    BadAge(std::num::ParseIntError),
+    EmptyRole,
 }
 
-impl MyError {
-    fn new(msg: &str) -> MyError {
-        MyError{details: msg.to_string()}
-    }
-}
-
-impl fmt::Display for MyError {
+impl fmt::Display for ParsePersonError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f,"{}",self.details)
+        match self {
+            ParsePersonError::Empty => write!(f, "input is empty"),
+            ParsePersonError::WrongFieldCount { got } => {
+                write!(f, "expected at least 2 fields, got {}", got)
+            }
+            ParsePersonError::MissingName => write!(f, "name is missing"),
+            ParsePersonError::BadAge(e) => write!(f, "invalid age: {}", e),
+            ParsePersonError::EmptyRole => write!(f, "role is empty"),
+        }
     }
 }
 
-impl std::error::Error for MyError {
-    fn description(&self) -> &str {
-        &self.details
+impl error::Error for ParsePersonError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParsePersonError::BadAge(e) => Some(e),
+            _ => None,
+        }
     }
 }
 
@@ -33,74 +42,63 @@ impl std::error::Error for MyError {
 struct Person {
     name: String,
     age: usize,
+    roles: Vec<String>,
+}
+
+impl fmt::Display for Person {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.name, self.age)?;
+        for role in &self.roles {
+            write!(f, ",{}", role)?;
+        }
+        Ok(())
+    }
 }
 
 // Steps:
 // 1. If the length of the provided string is 0, an error should be returned
 // 2. Split the given string on the commas present in it
-// 3. Only 2 elements should be returned from the split, otherwise return an error
+// 3. At least 2 elements should be returned from the split, otherwise return an error
 // 4. Extract the first element from the split operation and use it as the name
-// 5. Extract the other element from the split operation and parse it into a `usize` as the age
+// 5. Extract the second element from the split operation and parse it into a `usize` as the age
 //    with something like `"4".parse::<usize>()`
-// 5. If while extracting the name and the age something goes wrong, an error should be returned
+// 6. Any remaining elements are collected as the person's roles
+// 7. If while extracting the name, the age, or a role something goes wrong, an error should be returned
 // If everything goes well, then return a Result of a Person object
 
 impl FromStr for Person {
-    type Err = Box<dyn error::Error>;
+    type Err = ParsePersonError;
     fn from_str(s: &str) -> Result<Person, Self::Err> {
       if s.len() == 0 {
-        return Err(Box::new(MyError::new("Boom")))
+        return Err(ParsePersonError::Empty)
       }
 
-      let mut attributes: Vec<&str> = s.split(',').collect();
-      if attributes.len() != 2 {
-        return Err(Box::new(MyError::new("Boom")))
-      }
-      if attributes[0].len() == 0 {
-        return Err(Box::new(MyError::new("Boom")))
-      }
-      if attributes[1].len() == 0 {
-        return Err(Box::new(MyError::new("Boom")))
+      let attributes: Vec<&str> = s.split(',').collect();
+      if attributes.len() < 2 {
+        return Err(ParsePersonError::WrongFieldCount { got: attributes.len() })
       }
 
-      let age: usize;
-      let name: String;
-
-      match attributes.pop() {
-        Some(a) => {
-          match a.parse::<usize>() {
-              Ok(x) => {
-                age = x;
-              },
-              Err(e) => {
-                return Err(Box::new(MyError::new("Boom")))
-              },
-          };
-        },
-        None => {
-          return Err(Box::new(MyError::new("Boom")))
-        }
+      let name = attributes[0];
+      if name.len() == 0 {
+        return Err(ParsePersonError::MissingName)
       }
 
-      // 4. If the name is empty, then return the default of Person
-      match attributes.pop() {
-        Some(n) => {
-          if n.len() == 0 {
-            return Err(Box::new(MyError::new("Boom")))
-          } else {
-            name = n.to_string();
-          }
-        },
-        None => {
-          return Err(Box::new(MyError::new("Boom")))
+      let age = attributes[1]
+        .parse::<usize>()
+        .map_err(ParsePersonError::BadAge)?;
+
+      let mut roles = Vec::new();
+      for role in &attributes[2..] {
+        if role.len() == 0 {
+          return Err(ParsePersonError::EmptyRole)
         }
+        roles.push(role.to_string());
       }
-      // 5. Extract the other element from the split operation and parse it into a `usize` as the age
-      // If while parsing the age, something goes wrong, then return the default of Person
-      // Otherwise, then return an instantiated Person object with the results
+
       Ok(Person {
-        name: name,
+        name: name.to_string(),
         age: age,
+        roles: roles,
       })
     }
 }
@@ -116,7 +114,7 @@ mod tests {
 
     #[test]
     fn empty_input() {
-        assert!("".parse::<Person>().is_err());
+        assert!(matches!("".parse::<Person>(), Err(ParsePersonError::Empty)));
     }
     #[test]
     fn good_input() {
@@ -128,41 +126,84 @@ mod tests {
     }
     #[test]
     fn missing_age() {
-        assert!("John,".parse::<Person>().is_err());
+        assert!(matches!("John,".parse::<Person>(), Err(ParsePersonError::BadAge(_))));
     }
 
     #[test]
     fn invalid_age() {
-        assert!("John,twenty".parse::<Person>().is_err());
+        assert!(matches!("John,twenty".parse::<Person>(), Err(ParsePersonError::BadAge(_))));
     }
 
     #[test]
     fn missing_comma_and_age() {
-        assert!("John".parse::<Person>().is_err());
+        assert!(matches!(
+            "John".parse::<Person>(),
+            Err(ParsePersonError::WrongFieldCount { got: 1 })
+        ));
     }
 
     #[test]
     fn missing_name() {
-        assert!(",1".parse::<Person>().is_err());
+        assert!(matches!(",1".parse::<Person>(), Err(ParsePersonError::MissingName)));
     }
 
     #[test]
     fn missing_name_and_age() {
-        assert!(",".parse::<Person>().is_err());
+        assert!(matches!(",".parse::<Person>(), Err(ParsePersonError::MissingName)));
     }
 
     #[test]
     fn missing_name_and_invalid_age() {
-        assert!(",one".parse::<Person>().is_err());
+        assert!(matches!(",one".parse::<Person>(), Err(ParsePersonError::MissingName)));
     }
 
     #[test]
     fn trailing_comma() {
-        assert!("John,32,".parse::<Person>().is_err());
+        assert!(matches!(
+            "John,32,".parse::<Person>(),
+            Err(ParsePersonError::EmptyRole)
+        ));
     }
 
     #[test]
     fn trailing_comma_and_some_string() {
-        assert!("John,32,man".parse::<Person>().is_err());
+        let p = "John,32,man".parse::<Person>().unwrap();
+        assert_eq!(p.name, "John");
+        assert_eq!(p.age, 32);
+        assert_eq!(p.roles, vec!["man".to_string()]);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for s in [
+            "John,32",
+            "Mark,20",
+            "Alice,1",
+            "Mark,20,engineer,rustacean",
+        ] {
+            assert_eq!(s.parse::<Person>().unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn roles_default_to_empty() {
+        let p = "John,32".parse::<Person>().unwrap();
+        assert!(p.roles.is_empty());
+    }
+
+    #[test]
+    fn multiple_roles() {
+        let p = "Mark,20,engineer,rustacean".parse::<Person>().unwrap();
+        assert_eq!(p.name, "Mark");
+        assert_eq!(p.age, 20);
+        assert_eq!(p.roles, vec!["engineer".to_string(), "rustacean".to_string()]);
+    }
+
+    #[test]
+    fn empty_role() {
+        assert!(matches!(
+            "Mark,20,engineer,".parse::<Person>(),
+            Err(ParsePersonError::EmptyRole)
+        ));
     }
 }